@@ -306,7 +306,7 @@ impl DisplaySet<'_> {
                     }
 
                     let text = normalize_whitespace(text);
-                    let line_len = text.as_bytes().len();
+                    let line_len = text.len();
                     let left = self.margin.left(line_len);
                     let right = self.margin.right(line_len);
 
@@ -432,29 +432,27 @@ impl DisplaySet<'_> {
                             ann.annotation_part,
                             DisplayAnnotationPart::MultilineStart(_)
                         )
-                    }) {
-                        if let Some(max_pos) =
-                            annotations_positions.iter().map(|(pos, _)| *pos).max()
-                        {
-                            // Special case the following, so that we minimize overlapping multiline spans.
-                            //
-                            // 3 │       X0 Y0 Z0
-                            //   │ ┏━━━━━┛  │  │     < We are writing these lines
-                            //   │ ┃┌───────┘  │     < by reverting the "depth" of
-                            //   │ ┃│┌─────────┘     < their multiline spans.
-                            // 4 │ ┃││   X1 Y1 Z1
-                            // 5 │ ┃││   X2 Y2 Z2
-                            //   │ ┃│└────╿──│──┘ `Z` label
-                            //   │ ┃└─────│──┤
-                            //   │ ┗━━━━━━┥  `Y` is a good letter too
-                            //   ╰╴       `X` is a good letter
-                            for (pos, _) in &mut annotations_positions {
-                                *pos = max_pos - *pos;
-                            }
-                            // We know then that we don't need an additional line for the span label, saving us
-                            // one line of vertical space.
-                            line_len = line_len.saturating_sub(1);
+                    }) && let Some(max_pos) =
+                        annotations_positions.iter().map(|(pos, _)| *pos).max()
+                    {
+                        // Special case the following, so that we minimize overlapping multiline spans.
+                        //
+                        // 3 │       X0 Y0 Z0
+                        //   │ ┏━━━━━┛  │  │     < We are writing these lines
+                        //   │ ┃┌───────┘  │     < by reverting the "depth" of
+                        //   │ ┃│┌─────────┘     < their multiline spans.
+                        // 4 │ ┃││   X1 Y1 Z1
+                        // 5 │ ┃││   X2 Y2 Z2
+                        //   │ ┃│└────╿──│──┘ `Z` label
+                        //   │ ┃└─────│──┤
+                        //   │ ┗━━━━━━┥  `Y` is a good letter too
+                        //   ╰╴       `X` is a good letter
+                        for (pos, _) in &mut annotations_positions {
+                            *pos = max_pos - *pos;
                         }
+                        // We know then that we don't need an additional line for the span label, saving us
+                        // one line of vertical space.
+                        line_len = line_len.saturating_sub(1);
                     }
 
                     // This is a special case where we have a multiline
@@ -478,7 +476,7 @@ impl DisplaySet<'_> {
                         && annotations_positions.len() == 1
                         && annotations_positions
                             .first()
-                            .map_or(false, |(_, annotation)| {
+                            .is_some_and(|(_, annotation)| {
                                 matches!(
                                     annotation.annotation_part,
                                     DisplayAnnotationPart::MultilineStart(_)
@@ -1135,15 +1133,15 @@ fn format_header<'a>(
                 lineno,
                 ..
             } = item
+                && main_range >= range.0
+                && main_range < range.1 + max(*end_line as usize, 1)
             {
-                if main_range >= range.0 && main_range < range.1 + max(*end_line as usize, 1) {
-                    let char_column = text[0..(main_range - range.0).min(text.len())]
-                        .chars()
-                        .count();
-                    col = char_column + 1;
-                    line_offset = lineno.unwrap_or(1);
-                    break;
-                }
+                let char_column = text[0..(main_range - range.0).min(text.len())]
+                    .chars()
+                    .count();
+                col = char_column + 1;
+                line_offset = lineno.unwrap_or(1);
+                break;
             }
         }
 
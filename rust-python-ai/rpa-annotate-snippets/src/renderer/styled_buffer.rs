@@ -70,10 +70,8 @@ impl StyledBuffer {
     /// If `line` does not exist in our buffer, adds empty lines up to the given
     /// and fills the last line with unstyled whitespace.
     pub(crate) fn puts(&mut self, line: usize, col: usize, string: &str, style: Style) {
-        let mut n = col;
-        for c in string.chars() {
+        for (n, c) in (col..).zip(string.chars()) {
             self.putc(line, n, c, style);
-            n += 1;
         }
     }
     /// For given `line` inserts `string` with `style` after old content of that line,
@@ -69,10 +69,10 @@ pub(crate) fn derive_cache_key(item: &DeriveInput) -> syn::Result<TokenStream> {
             let mut fields = Vec::with_capacity(item_struct.fields.len());
 
             for (i, field) in item_struct.fields.iter().enumerate() {
-                if let Some(cache_field_attribute) = cache_key_field_attribute(field)? {
-                    if cache_field_attribute.ignore {
-                        continue;
-                    }
+                if let Some(cache_field_attribute) = cache_key_field_attribute(field)?
+                    && cache_field_attribute.ignore
+                {
+                    continue;
                 }
 
                 let field_attr = if let Some(ident) = &field.ident {
@@ -46,14 +46,14 @@ pub(crate) fn derive_impl(input: DeriveInput) -> syn::Result<TokenStream> {
                     // by calling `Type::record` instead of `visitor.visit_set`
                     if let (Type::Path(ty), Meta::List(list)) = (&field.ty, &serde.meta) {
                         for token in list.tokens.clone() {
-                            if let TokenTree::Ident(ident) = token {
-                                if ident == "flatten" {
-                                    output.push(quote_spanned!(
-                                        ty.span() => (<#ty>::record(visit))
-                                    ));
-
-                                    break;
-                                }
+                            if let TokenTree::Ident(ident) = token
+                                && ident == "flatten"
+                            {
+                                output.push(quote_spanned!(
+                                    ty.span() => (<#ty>::record(visit))
+                                ));
+
+                                break;
                             }
                         }
                     }
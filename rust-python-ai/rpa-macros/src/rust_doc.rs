@@ -48,13 +48,12 @@ fn parse_attr<'a, const LEN: usize>(
             .iter()
             .map(|segment| &segment.ident);
 
-        if path_idents.eq(path) {
-            if let syn::Expr::Lit(syn::ExprLit {
+        if path_idents.eq(path)
+            && let syn::Expr::Lit(syn::ExprLit {
                 lit: Lit::Str(lit), ..
             }) = &name_value.value
-            {
-                return Some(lit);
-            }
+        {
+            return Some(lit);
         }
     }
 
@@ -54,13 +54,12 @@ fn parse_attr<'a, const LEN: usize>(
             .iter()
             .map(|segment| &segment.ident);
 
-        if itertools::equal(path_idents, path) {
-            if let syn::Expr::Lit(syn::ExprLit {
+        if itertools::equal(path_idents, path)
+            && let syn::Expr::Lit(syn::ExprLit {
                 lit: Lit::Str(lit), ..
             }) = &name_value.value
-            {
-                return Some(lit);
-            }
+        {
+            return Some(lit);
         }
     }
 
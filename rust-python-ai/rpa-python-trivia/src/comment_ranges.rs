@@ -1,7 +1,7 @@
 use std::fmt::{Debug, Formatter};
 use std::ops::Deref;
 
-use rpa_source_file::LineRanges;
+use rpa_source_file::{LineRanges, UniversalNewlines};
 use rpa_text_size::{Ranged, TextRange, TextSize};
 
 use crate::{has_leading_content, has_trailing_content, is_python_whitespace};
@@ -152,6 +152,30 @@ impl CommentRanges {
         block_comments
     }
 
+    /// Returns the fraction of non-blank lines in `source` that are comments or contain a
+    /// trailing comment. Returns `0.0` if `source` has no non-blank lines.
+    pub fn comment_density(&self, source: &str) -> f64 {
+        let mut code_lines = 0u32;
+        let mut comment_lines = 0u32;
+
+        for line in source.universal_newlines() {
+            if line.trim_start_matches(is_python_whitespace).is_empty() {
+                continue;
+            }
+
+            code_lines += 1;
+            if self.intersects(line.range()) {
+                comment_lines += 1;
+            }
+        }
+
+        if code_lines == 0 {
+            0.0
+        } else {
+            f64::from(comment_lines) / f64::from(code_lines)
+        }
+    }
+
     /// Returns `true` if the given range is an empty comment.
     fn is_empty(range: TextRange, source: &str) -> bool {
         source[range].chars().skip(1).all(is_python_whitespace)
@@ -186,3 +210,31 @@ impl<'a> IntoIterator for &'a CommentRanges {
         self.raw.iter().copied()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rpa_text_size::{TextRange, TextSize};
+
+    use super::CommentRanges;
+
+    #[test]
+    fn comment_density_commented() {
+        let source = "# header\n\nx = 1  # trailing\n# own line\ny = 2\n";
+        let comment_ranges = CommentRanges::new(vec![
+            TextRange::new(TextSize::from(0), TextSize::from(8)),
+            TextRange::new(TextSize::from(17), TextSize::from(27)),
+            TextRange::new(TextSize::from(28), TextSize::from(38)),
+        ]);
+
+        assert_eq!(comment_ranges.comment_density(source), 0.75);
+    }
+
+    #[test]
+    fn comment_density_no_comments() {
+        let source = "x = 1\n\ny = 2\n";
+        let comment_ranges = CommentRanges::default();
+
+        assert_eq!(comment_ranges.comment_density(source), 0.0);
+        assert_eq!(comment_ranges.comment_density(""), 0.0);
+    }
+}
@@ -11,12 +11,14 @@ pub use crate::line_index::{LineIndex, OneIndexed, PositionEncoding};
 pub use crate::line_ranges::LineRanges;
 pub use crate::newlines::{
     Line, LineEnding, NewlineWithTrailingNewline, UniversalNewlineIterator, UniversalNewlines,
-    find_newline,
+    find_newline, normalize_newlines,
 };
+pub use crate::registry::SourceFileRegistry;
 
 mod line_index;
 mod line_ranges;
 mod newlines;
+mod registry;
 
 #[derive(Debug)]
 pub struct SourceCode<'src, 'index> {
@@ -88,6 +90,27 @@ impl<'src, 'index> SourceCode<'src, 'index> {
         &self.text[range]
     }
 
+    /// Returns the range and text of the lines surrounding `offset`, including up to
+    /// `lines_before` lines above and `lines_after` lines below the line containing `offset`.
+    ///
+    /// The window is clamped to the start and end of the file. The range includes the trailing
+    /// newline of the last line in the window, if any.
+    pub fn context_window(
+        &self,
+        offset: TextSize,
+        lines_before: usize,
+        lines_after: usize,
+    ) -> (TextRange, &'src str) {
+        let line = self.line_index(offset);
+        let last_line = OneIndexed::from_zero_indexed(self.line_count() - 1);
+
+        let first = line.saturating_sub(lines_before);
+        let last = line.saturating_add(lines_after).min(last_line);
+
+        let range = TextRange::new(self.line_start(first), self.line_end(last));
+        (range, &self.text[range])
+    }
+
     pub fn text(&self) -> &'src str {
         self.text
     }
@@ -148,7 +171,6 @@ impl SourceFileBuilder {
     }
 }
 
-///
 #[derive(Clone, Eq, PartialEq)]
 #[cfg_attr(feature = "get-size", derive(get-size2::GetSize))]
 pub struct SourceFile {
@@ -175,7 +197,7 @@ impl SourceFile {
         &self.source_text()[range]
     }
 
-    pub fn to_source_code(&self) -> SourceCode {
+    pub fn to_source_code(&self) -> SourceCode<'_, '_> {
         SourceCode {
             text: self.source_text(),
             index: self.index(),
@@ -226,7 +248,6 @@ impl PartialEq for SourceFileInner {
 
 impl Eq for SourceFileInner {}
 
-///
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LineColumn {
@@ -258,11 +279,42 @@ impl std::fmt::Display for LineColumn {
     }
 }
 
+impl LineColumn {
+    /// Converts this line and column into a [`SourceLocation`] whose character offset is
+    /// measured in `encoding`.
+    ///
+    /// The inverse of [`SourceLocation::to_line_column`].
+    pub fn to_source_location(
+        &self,
+        source: &str,
+        index: &LineIndex,
+        encoding: PositionEncoding,
+    ) -> SourceLocation {
+        // `LineColumn` doesn't count the BOM as a column on the first line, `SourceLocation` does.
+        let character_offset = if self.line.to_zero_indexed() == 0 && source.starts_with('\u{feff}')
+        {
+            self.column.saturating_add(1)
+        } else {
+            self.column
+        };
+
+        let offset = index.offset(
+            SourceLocation {
+                line: self.line,
+                character_offset,
+            },
+            source,
+            PositionEncoding::Utf32,
+        );
+
+        index.source_location(offset, source, encoding)
+    }
+}
+
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SourceLocation {
     pub line: OneIndexed,
-    ///
     pub character_offset: OneIndexed,
 }
 
@@ -275,6 +327,22 @@ impl Default for SourceLocation {
     }
 }
 
+impl SourceLocation {
+    /// Converts this location, whose character offset is measured in `encoding`, into a
+    /// [`LineColumn`].
+    ///
+    /// The inverse of [`LineColumn::to_source_location`].
+    pub fn to_line_column(
+        &self,
+        source: &str,
+        index: &LineIndex,
+        encoding: PositionEncoding,
+    ) -> LineColumn {
+        let offset = index.offset(*self, source, encoding);
+        index.line_column(offset, source)
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub enum SourceRow {
     Notebook { cell: OneIndexed, line: OneIndexed },
@@ -289,3 +357,114 @@ impl Display for SourceRow {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rpa_text_size::{TextRange, TextSize};
+
+    use crate::{LineColumn, LineIndex, OneIndexed, PositionEncoding, SourceCode, SourceLocation};
+
+    const CONTENTS: &str = "a = 1\nb = 2\nc = 3\nd = 4\ne = 5\n";
+
+    #[test]
+    fn context_window_middle() {
+        let index = LineIndex::from_source_text(CONTENTS);
+        let source = SourceCode::new(CONTENTS, &index);
+
+        // Offset inside `c = 3`.
+        let (range, text) = source.context_window(TextSize::from(14), 1, 1);
+        assert_eq!(range, TextRange::new(TextSize::from(6), TextSize::from(24)));
+        assert_eq!(text, "b = 2\nc = 3\nd = 4\n");
+    }
+
+    #[test]
+    fn context_window_top() {
+        let index = LineIndex::from_source_text(CONTENTS);
+        let source = SourceCode::new(CONTENTS, &index);
+
+        let (range, text) = source.context_window(TextSize::from(2), 3, 1);
+        assert_eq!(range, TextRange::new(TextSize::from(0), TextSize::from(12)));
+        assert_eq!(text, "a = 1\nb = 2\n");
+    }
+
+    #[test]
+    fn context_window_bottom() {
+        let index = LineIndex::from_source_text(CONTENTS);
+        let source = SourceCode::new(CONTENTS, &index);
+
+        // Offset inside `e = 5`.
+        let (range, text) = source.context_window(TextSize::from(26), 1, 5);
+        assert_eq!(
+            range,
+            TextRange::new(TextSize::from(18), TextSize::from(30))
+        );
+        assert_eq!(text, "d = 4\ne = 5\n");
+
+        // Offset at the very end of the file.
+        let (range, text) = source.context_window(TextSize::from(30), 0, 5);
+        assert_eq!(range, TextRange::empty(TextSize::from(30)));
+        assert_eq!(text, "");
+    }
+
+    #[test]
+    fn source_location_line_column_round_trip() {
+        let contents = "a = 1\nx = '🫣' + y";
+        let index = LineIndex::from_source_text(contents);
+
+        // The `+` on the second line.
+        let offset = TextSize::from(17);
+        let line_column = LineColumn {
+            line: OneIndexed::from_zero_indexed(1),
+            column: OneIndexed::from_zero_indexed(8),
+        };
+        assert_eq!(index.line_column(offset, contents), line_column);
+
+        for (encoding, character_offset) in [
+            (PositionEncoding::Utf8, 11),
+            (PositionEncoding::Utf16, 9),
+            (PositionEncoding::Utf32, 8),
+        ] {
+            let location = SourceLocation {
+                line: OneIndexed::from_zero_indexed(1),
+                character_offset: OneIndexed::from_zero_indexed(character_offset),
+            };
+            assert_eq!(index.source_location(offset, contents, encoding), location);
+
+            assert_eq!(
+                location.to_line_column(contents, &index, encoding),
+                line_column
+            );
+            assert_eq!(
+                line_column.to_source_location(contents, &index, encoding),
+                location
+            );
+        }
+    }
+
+    #[test]
+    fn source_location_line_column_bom() {
+        let contents = "\u{feff}x = '🫣'";
+        let index = LineIndex::from_source_text(contents);
+
+        // The closing quote.
+        let offset = TextSize::from(12);
+        let line_column = index.line_column(offset, contents);
+        assert_eq!(line_column.column, OneIndexed::from_zero_indexed(6));
+
+        for encoding in [
+            PositionEncoding::Utf8,
+            PositionEncoding::Utf16,
+            PositionEncoding::Utf32,
+        ] {
+            let location = index.source_location(offset, contents, encoding);
+            assert_eq!(
+                location.to_line_column(contents, &index, encoding),
+                line_column
+            );
+            assert_eq!(
+                line_column.to_source_location(contents, &index, encoding),
+                location
+            );
+        }
+    }
+}
@@ -10,7 +10,6 @@ use rpa_text_size::{TextLen, TextRange, TextSize};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-///
 #[derive(Clone, Eq, PartialEq)]
 #[cfg_attr(feature = "get-size", derive(get-size2::GetSize))]
 pub struct LineIndex {
@@ -329,7 +328,6 @@ impl IndexKind {
     }
 }
 
-///
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct OneIndexed(NonZeroUsize);
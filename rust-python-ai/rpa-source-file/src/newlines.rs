@@ -58,6 +58,30 @@ pub fn find_newline(text: &str) -> Option<(usize, LineEnding)> {
     }
 }
 
+/// Replaces every `\n`, `\r\n`, and `\r` line ending in `text` with `target`.
+///
+/// Returns the normalized text and the number of line endings that were changed.
+pub fn normalize_newlines(text: &str, target: LineEnding) -> (String, usize) {
+    let mut normalized = String::with_capacity(text.len());
+    let mut changed = 0;
+    let mut rest = text;
+
+    while let Some((position, line_ending)) = find_newline(rest) {
+        normalized.push_str(&rest[..position]);
+        normalized.push_str(target.as_str());
+
+        if line_ending != target {
+            changed += 1;
+        }
+
+        rest = &rest[position + line_ending.len()..];
+    }
+
+    normalized.push_str(rest);
+
+    (normalized, changed)
+}
+
 impl<'a> Iterator for UniversalNewlineIterator<'a> {
     type Item = Line<'a>;
 
@@ -325,7 +349,7 @@ impl Deref for LineEnding {
 mod tests {
     use rpa_text_size::TextSize;
 
-    use super::{Line, UniversalNewlineIterator};
+    use super::{Line, LineEnding, UniversalNewlineIterator, normalize_newlines};
 
     #[test]
     fn universal_newlines_empty_str() {
@@ -429,4 +453,35 @@ mod tests {
         );
         assert_eq!(lines.next(), None);
     }
+
+    #[test]
+    fn normalize_newlines_mixed() {
+        let source = "foo\nbar\r\nbaz\rbop\n";
+
+        assert_eq!(
+            normalize_newlines(source, LineEnding::Lf),
+            ("foo\nbar\nbaz\nbop\n".to_string(), 2)
+        );
+        assert_eq!(
+            normalize_newlines(source, LineEnding::CrLf),
+            ("foo\r\nbar\r\nbaz\r\nbop\r\n".to_string(), 3)
+        );
+        assert_eq!(
+            normalize_newlines(source, LineEnding::Cr),
+            ("foo\rbar\rbaz\rbop\r".to_string(), 3)
+        );
+    }
+
+    #[test]
+    fn normalize_newlines_unchanged() {
+        assert_eq!(
+            normalize_newlines("foo\nbar", LineEnding::Lf),
+            ("foo\nbar".to_string(), 0)
+        );
+        assert_eq!(normalize_newlines("", LineEnding::CrLf), (String::new(), 0));
+        assert_eq!(
+            normalize_newlines("\r\n\r\n", LineEnding::CrLf),
+            ("\r\n\r\n".to_string(), 0)
+        );
+    }
 }
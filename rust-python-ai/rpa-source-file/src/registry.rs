@@ -0,0 +1,104 @@
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use crate::{SourceFile, SourceFileBuilder};
+
+/// Interns [`SourceFile`]s by name.
+///
+/// Requesting a file whose name and content match a registered file returns the shared instance.
+/// Requesting a file with a known name but different content replaces the registered file with
+/// a new one. Previously handed out instances are unaffected because [`SourceFile`] is immutable.
+#[derive(Debug, Default)]
+pub struct SourceFileRegistry {
+    files: Mutex<HashMap<Box<str>, SourceFile>>,
+}
+
+impl SourceFileRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get_or_insert(&self, name: &str, code: &str) -> SourceFile {
+        let mut files = self.files();
+
+        if let Some(file) = files.get(name)
+            && file.source_text() == code
+        {
+            return file.clone();
+        }
+
+        let file = SourceFileBuilder::new(name, code).finish();
+        files.insert(name.into(), file.clone());
+        file
+    }
+
+    pub fn get(&self, name: &str) -> Option<SourceFile> {
+        self.files().get(name).cloned()
+    }
+
+    pub fn remove(&self, name: &str) -> Option<SourceFile> {
+        self.files().remove(name)
+    }
+
+    pub fn len(&self) -> usize {
+        self.files().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.files().is_empty()
+    }
+
+    fn files(&self) -> MutexGuard<'_, HashMap<Box<str>, SourceFile>> {
+        // The map is never left in an inconsistent state, so it's safe to ignore poisoning.
+        self.files.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::SourceFileRegistry;
+
+    #[test]
+    fn same_content_is_shared() {
+        let registry = SourceFileRegistry::new();
+
+        let first = registry.get_or_insert("main.py", "x = 1\n");
+        let second = registry.get_or_insert("main.py", "x = 1\n");
+
+        assert!(Arc::ptr_eq(&first.inner, &second.inner));
+        assert_eq!(registry.len(), 1);
+    }
+
+    #[test]
+    fn changed_content_replaces() {
+        let registry = SourceFileRegistry::new();
+
+        let first = registry.get_or_insert("main.py", "x = 1\n");
+        let second = registry.get_or_insert("main.py", "x = 2\n");
+
+        assert!(!Arc::ptr_eq(&first.inner, &second.inner));
+        assert_eq!(first.source_text(), "x = 1\n");
+        assert_eq!(second.source_text(), "x = 2\n");
+        assert_eq!(registry.len(), 1);
+
+        let registered = registry.get("main.py").unwrap();
+        assert!(Arc::ptr_eq(&registered.inner, &second.inner));
+    }
+
+    #[test]
+    fn names_are_distinct() {
+        let registry = SourceFileRegistry::new();
+
+        let a = registry.get_or_insert("a.py", "x = 1\n");
+        let b = registry.get_or_insert("b.py", "x = 1\n");
+
+        assert!(!Arc::ptr_eq(&a.inner, &b.inner));
+        assert_eq!(registry.len(), 2);
+
+        let removed = registry.remove("a.py").unwrap();
+        assert!(Arc::ptr_eq(&removed.inner, &a.inner));
+        assert!(registry.get("a.py").is_none());
+    }
+}